@@ -0,0 +1,585 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::Serialize;
+#[cfg(target_os = "macos")]
+use tauri::AboutMetadata;
+use tauri::{
+    CustomMenuItem, Manager, Menu, MenuItem, State, Submenu, SystemTray, SystemTrayEvent,
+    SystemTrayMenu, SystemTrayMenuItem, Window, WindowEvent,
+};
+
+#[derive(Clone, Serialize)]
+struct ProjectPayload {
+    path: Option<String>,
+}
+
+/// Tracks the project that is currently open in the editor, if any.
+/// `open` is "open for editing" and is independent of `path`: a brand
+/// new project is open but has no path until the first save.
+#[derive(Default)]
+struct ProjectState {
+    path: Option<PathBuf>,
+    open: bool,
+}
+
+/// Owns the lifecycle of the currently open project and is held in Tauri
+/// managed state so both menu events and frontend commands can share it.
+struct ProjectManager {
+    state: Mutex<ProjectState>,
+}
+
+impl ProjectManager {
+    fn new() -> Self {
+        Self {
+            state: Mutex::new(ProjectState::default()),
+        }
+    }
+
+    fn path(&self) -> Option<PathBuf> {
+        self.state.lock().unwrap().path.clone()
+    }
+
+    fn is_open(&self) -> bool {
+        self.state.lock().unwrap().open
+    }
+
+    /// Starts a new, unsaved project: open for editing, no path yet.
+    fn open_new(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.path = None;
+        state.open = true;
+    }
+
+    fn open(&self, path: PathBuf) {
+        let mut state = self.state.lock().unwrap();
+        state.path = Some(path);
+        state.open = true;
+    }
+
+    fn save(&self, path: PathBuf) {
+        let mut state = self.state.lock().unwrap();
+        state.path = Some(path);
+        state.open = true;
+    }
+
+    fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.path = None;
+        state.open = false;
+    }
+}
+
+const MAX_RECENT_PROJECTS: usize = 10;
+
+/// Tracks recently opened/saved projects and persists them as JSON under
+/// the app config dir so the list survives across launches.
+struct RecentProjects {
+    file_path: Option<PathBuf>,
+    paths: Mutex<Vec<PathBuf>>,
+}
+
+impl RecentProjects {
+    fn new(file_path: Option<PathBuf>) -> Self {
+        let paths = file_path
+            .as_deref()
+            .map(Self::load)
+            .unwrap_or_default();
+        Self {
+            file_path,
+            paths: Mutex::new(paths),
+        }
+    }
+
+    fn load(file_path: &Path) -> Vec<PathBuf> {
+        fs::read_to_string(file_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn persist(&self) {
+        let Some(file_path) = &self.file_path else {
+            return;
+        };
+        if let Some(parent) = file_path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(json) = serde_json::to_string(&*self.paths.lock().unwrap()) {
+            let _ = fs::write(file_path, json);
+        }
+    }
+
+    fn push(&self, project_path: PathBuf) {
+        let mut paths = self.paths.lock().unwrap();
+        paths.retain(|p| p != &project_path);
+        paths.insert(0, project_path);
+        paths.truncate(MAX_RECENT_PROJECTS);
+        drop(paths);
+        self.persist();
+    }
+
+    fn clear(&self) {
+        self.paths.lock().unwrap().clear();
+        self.persist();
+    }
+
+    fn snapshot(&self) -> Vec<PathBuf> {
+        self.paths.lock().unwrap().clone()
+    }
+}
+
+/// Static metadata resolved once at startup and reused whenever the menu
+/// needs to be rebuilt (e.g. when the recent-projects list changes).
+struct AppMeta {
+    pkg_name: String,
+}
+
+fn pick_open_path() -> Option<PathBuf> {
+    tauri::api::dialog::blocking::FileDialogBuilder::new().pick_file()
+}
+
+fn pick_save_path() -> Option<PathBuf> {
+    tauri::api::dialog::blocking::FileDialogBuilder::new().save_file()
+}
+
+/// Enables or disables the Save/Save As/Close items to match whether a
+/// project is currently open.
+fn set_project_menu_enabled(window: &Window, enabled: bool) {
+    let menu_handle = window.menu_handle();
+    for id in ["save_project", "save_as_project", "close_project"] {
+        menu_handle.get_item(id).set_enabled(enabled).unwrap();
+    }
+}
+
+/// Rebuilds the window menu so the "Open Recent" submenu reflects the
+/// latest list. Tauri's menu items can be toggled in place, but entries
+/// can't be inserted/removed, so a change in the recent list means
+/// rebuilding the whole menu. `project_open` must reflect the current
+/// `ProjectManager` state so the rebuild doesn't clobber the Save/Save
+/// As/Close enabled state that `set_project_menu_enabled` set moments
+/// earlier.
+fn refresh_recent_menu(
+    window: &Window,
+    recent: &RecentProjects,
+    app_meta: &AppMeta,
+    project_open: bool,
+) {
+    let menu = build_menu(&app_meta.pkg_name, &recent.snapshot(), project_open);
+    window.set_menu(menu).unwrap();
+}
+
+#[tauri::command]
+fn new_project(window: Window, manager: State<ProjectManager>) {
+    manager.open_new();
+    set_project_menu_enabled(&window, true);
+    window
+        .emit("project-opened", ProjectPayload { path: None })
+        .unwrap();
+}
+
+#[tauri::command]
+fn open_project(
+    window: Window,
+    manager: State<ProjectManager>,
+    recent: State<RecentProjects>,
+    app_meta: State<AppMeta>,
+) {
+    if let Some(path) = pick_open_path() {
+        manager.open(path.clone());
+        recent.push(path.clone());
+        refresh_recent_menu(&window, &recent, &app_meta, true);
+        window
+            .emit(
+                "project-opened",
+                ProjectPayload {
+                    path: Some(path.display().to_string()),
+                },
+            )
+            .unwrap();
+    }
+}
+
+#[tauri::command]
+fn save_project(
+    window: Window,
+    manager: State<ProjectManager>,
+    recent: State<RecentProjects>,
+    app_meta: State<AppMeta>,
+) {
+    let path = manager.path().or_else(pick_save_path);
+    if let Some(path) = path {
+        manager.save(path.clone());
+        recent.push(path.clone());
+        refresh_recent_menu(&window, &recent, &app_meta, true);
+        window
+            .emit(
+                "project-saved",
+                ProjectPayload {
+                    path: Some(path.display().to_string()),
+                },
+            )
+            .unwrap();
+    }
+}
+
+#[tauri::command]
+fn save_as_project(
+    window: Window,
+    manager: State<ProjectManager>,
+    recent: State<RecentProjects>,
+    app_meta: State<AppMeta>,
+) {
+    if let Some(path) = pick_save_path() {
+        manager.save(path.clone());
+        recent.push(path.clone());
+        refresh_recent_menu(&window, &recent, &app_meta, true);
+        window
+            .emit(
+                "project-saved",
+                ProjectPayload {
+                    path: Some(path.display().to_string()),
+                },
+            )
+            .unwrap();
+    }
+}
+
+/// Opens the project at `index` in the persisted recent-projects list.
+#[tauri::command]
+fn open_recent_project(
+    window: Window,
+    index: usize,
+    manager: State<ProjectManager>,
+    recent: State<RecentProjects>,
+    app_meta: State<AppMeta>,
+) {
+    let Some(path) = recent.snapshot().get(index).cloned() else {
+        return;
+    };
+    manager.open(path.clone());
+    recent.push(path.clone());
+    refresh_recent_menu(&window, &recent, &app_meta, true);
+    window
+        .emit(
+            "project-opened",
+            ProjectPayload {
+                path: Some(path.display().to_string()),
+            },
+        )
+        .unwrap();
+}
+
+#[tauri::command]
+fn close_project(window: Window, manager: State<ProjectManager>) {
+    manager.close();
+    set_project_menu_enabled(&window, false);
+    window
+        .emit("project-closed", ProjectPayload { path: None })
+        .unwrap();
+}
+
+/// Builds the "Open Recent" submenu from the persisted list, with each
+/// item id encoding its index (`recent::<index>`) and a trailing entry
+/// to clear the list.
+fn build_recent_menu(recent: &[PathBuf]) -> Submenu {
+    let mut menu = Menu::new();
+    if recent.is_empty() {
+        menu = menu.add_item(
+            CustomMenuItem::new("recent_empty".to_string(), "No Recent Projects").disabled(),
+        );
+    } else {
+        for (index, path) in recent.iter().enumerate() {
+            menu = menu.add_item(CustomMenuItem::new(
+                format!("recent::{index}"),
+                path.display().to_string(),
+            ));
+        }
+        menu = menu
+            .add_native_item(MenuItem::Separator)
+            .add_item(CustomMenuItem::new(
+                "recent_clear".to_string(),
+                "Clear Recent",
+            ));
+    }
+    Submenu::new("Open Recent", menu)
+}
+
+/// Builds the application menu. macOS gets a native leading application
+/// submenu (About/Services/Hide/Quit) ahead of the File/Edit/Help layout
+/// shared by every platform, matching OS menu conventions. `project_open`
+/// controls the initial enabled state of Save/Save As/Close so a menu
+/// rebuild (e.g. from the Open Recent list changing) doesn't regress the
+/// enable/disable state set by `set_project_menu_enabled`.
+fn build_menu(pkg_name: &str, recent: &[PathBuf], project_open: bool) -> Menu {
+    // here `"quit".to_string()` defines the menu item id, and the second parameter is the menu item label.
+    let new_project_item = CustomMenuItem::new("new_project".to_string(), "New Project")
+        .accelerator("CmdOrCtrl+N");
+    let open_project_item = CustomMenuItem::new("open_project".to_string(), "Open Project")
+        .accelerator("CmdOrCtrl+O");
+    let mut close_project_item =
+        CustomMenuItem::new("close_project".to_string(), "Close Project")
+            .accelerator("CmdOrCtrl+W");
+    let mut save_project_item = CustomMenuItem::new("save_project".to_string(), "Save Project")
+        .accelerator("CmdOrCtrl+S");
+    let mut save_as_project_item =
+        CustomMenuItem::new("save_as_project".to_string(), "Save As Project")
+            .accelerator("CmdOrCtrl+Shift+S");
+    if !project_open {
+        close_project_item = close_project_item.disabled();
+        save_project_item = save_project_item.disabled();
+        save_as_project_item = save_as_project_item.disabled();
+    }
+    let mut file_items = Menu::new()
+        .add_item(new_project_item)
+        .add_item(open_project_item)
+        .add_submenu(build_recent_menu(recent))
+        .add_item(close_project_item)
+        .add_native_item(MenuItem::Separator)
+        .add_item(save_project_item)
+        .add_item(save_as_project_item);
+
+    // macOS gets Quit from the native app submenu below, so a custom
+    // Exit item here would be a second, redundant quit entry.
+    #[cfg(not(target_os = "macos"))]
+    {
+        let exit = CustomMenuItem::new("exit".to_string(), "Exit");
+        file_items = file_items
+            .add_native_item(MenuItem::Separator)
+            .add_item(exit);
+    }
+
+    let file_menu = Submenu::new("File", file_items);
+    let edit_menu = Submenu::new("Edit", Menu::new().add_native_item(MenuItem::Copy));
+
+    let mut menu = Menu::new();
+
+    #[cfg(target_os = "macos")]
+    {
+        let app_menu = Submenu::new(
+            pkg_name,
+            Menu::new()
+                .add_native_item(MenuItem::About(
+                    pkg_name.to_string(),
+                    AboutMetadata::new(),
+                ))
+                .add_native_item(MenuItem::Separator)
+                .add_native_item(MenuItem::Services)
+                .add_native_item(MenuItem::Separator)
+                .add_native_item(MenuItem::Hide)
+                .add_native_item(MenuItem::HideOthers)
+                .add_native_item(MenuItem::ShowAll)
+                .add_native_item(MenuItem::Separator)
+                .add_native_item(MenuItem::Quit),
+        );
+        menu = menu.add_submenu(app_menu);
+    }
+
+    menu = menu.add_submenu(file_menu).add_submenu(edit_menu);
+
+    // macOS already has About in the native app submenu above, so a
+    // Help menu that only held the custom About item would duplicate it.
+    #[cfg(not(target_os = "macos"))]
+    {
+        let about = CustomMenuItem::new("about".to_string(), "About");
+        let help_menu = Submenu::new("Help", Menu::new().add_item(about));
+        menu = menu.add_submenu(help_menu);
+    }
+
+    menu
+}
+
+/// Terminates the process. This is the only true exit path: the tray's
+/// "Exit" item and (on non-macOS, where Quit isn't native) File ▸ Exit
+/// both route through it, since closing the window itself only hides it
+/// to the tray.
+fn exit_app() {
+    std::process::exit(0);
+}
+
+/// Builds the tray menu. "Exit" here is the only path that actually
+/// terminates the process; closing the window just hides it.
+fn build_tray() -> SystemTray {
+    let tray_menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new("tray_show".to_string(), "Show"))
+        .add_item(CustomMenuItem::new("tray_hide".to_string(), "Hide"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new("tray_exit".to_string(), "Exit"));
+    SystemTray::new().with_menu(tray_menu)
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    let context = tauri::generate_context!();
+    let pkg_name = context.package_info().name.clone();
+    let recent_projects_path =
+        tauri::api::path::app_config_dir(context.config()).map(|dir| dir.join("recent_projects.json"));
+    let recent_projects = RecentProjects::new(recent_projects_path);
+    let menu = build_menu(&pkg_name, &recent_projects.snapshot(), false);
+
+    tauri::Builder::default()
+        .manage(ProjectManager::new())
+        .manage(recent_projects)
+        .manage(AppMeta { pkg_name })
+        .menu(menu)
+        .system_tray(build_tray())
+        .invoke_handler(tauri::generate_handler![
+            new_project,
+            open_project,
+            save_project,
+            save_as_project,
+            close_project,
+            open_recent_project,
+        ])
+        .on_menu_event(|event| {
+            let window = event.window().clone();
+            let manager: State<ProjectManager> = event.window().state();
+            let recent: State<RecentProjects> = event.window().state();
+            let app_meta: State<AppMeta> = event.window().state();
+            let id = event.menu_item_id();
+            if let Some(index) = id.strip_prefix("recent::") {
+                if let Ok(index) = index.parse::<usize>() {
+                    open_recent_project(window, index, manager, recent, app_meta);
+                }
+                return;
+            }
+            match id {
+                "exit" => exit_app(),
+                "new_project" => new_project(window, manager),
+                "open_project" => open_project(window, manager, recent, app_meta),
+                "save_project" => save_project(window, manager, recent, app_meta),
+                "save_as_project" => save_as_project(window, manager, recent, app_meta),
+                "close_project" => close_project(window, manager),
+                "recent_clear" => {
+                    recent.clear();
+                    refresh_recent_menu(&window, &recent, &app_meta, manager.is_open());
+                }
+                _ => {}
+            }
+        })
+        .on_system_tray_event(|app, event| match event {
+            SystemTrayEvent::LeftClick { .. } => {
+                if let Some(window) = app.get_window("main") {
+                    window.show().unwrap();
+                    window.set_focus().unwrap();
+                }
+            }
+            SystemTrayEvent::MenuItemClick { id, .. } => {
+                let window = app.get_window("main");
+                match id.as_str() {
+                    "tray_show" => {
+                        if let Some(window) = window {
+                            window.show().unwrap();
+                            window.set_focus().unwrap();
+                        }
+                    }
+                    "tray_hide" => {
+                        if let Some(window) = window {
+                            window.hide().unwrap();
+                        }
+                    }
+                    "tray_exit" => exit_app(),
+                    _ => {}
+                }
+            }
+            _ => {}
+        })
+        .on_window_event(|event| {
+            if let WindowEvent::CloseRequested { api, .. } = event.event() {
+                event.window().hide().unwrap();
+                api.prevent_close();
+            }
+        })
+        .run(context)
+        .expect("error while running tauri application");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn unique_temp_file() -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!(
+            "archon_recent_projects_test_{}_{n}.json",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn push_adds_most_recent_first() {
+        let recent = RecentProjects::new(None);
+        recent.push(PathBuf::from("/a"));
+        recent.push(PathBuf::from("/b"));
+        assert_eq!(
+            recent.snapshot(),
+            vec![PathBuf::from("/b"), PathBuf::from("/a")]
+        );
+    }
+
+    #[test]
+    fn push_dedups_by_moving_existing_entry_to_front() {
+        let recent = RecentProjects::new(None);
+        recent.push(PathBuf::from("/a"));
+        recent.push(PathBuf::from("/b"));
+        recent.push(PathBuf::from("/a"));
+        assert_eq!(
+            recent.snapshot(),
+            vec![PathBuf::from("/a"), PathBuf::from("/b")]
+        );
+    }
+
+    #[test]
+    fn push_truncates_at_max_recent_projects() {
+        let recent = RecentProjects::new(None);
+        for i in 0..MAX_RECENT_PROJECTS + 5 {
+            recent.push(PathBuf::from(format!("/project-{i}")));
+        }
+        let snapshot = recent.snapshot();
+        assert_eq!(snapshot.len(), MAX_RECENT_PROJECTS);
+        assert_eq!(
+            snapshot[0],
+            PathBuf::from(format!("/project-{}", MAX_RECENT_PROJECTS + 4))
+        );
+    }
+
+    #[test]
+    fn clear_empties_the_list() {
+        let recent = RecentProjects::new(None);
+        recent.push(PathBuf::from("/a"));
+        recent.clear();
+        assert!(recent.snapshot().is_empty());
+    }
+
+    #[test]
+    fn load_returns_empty_when_file_is_missing() {
+        let path = unique_temp_file();
+        assert_eq!(RecentProjects::load(&path), Vec::<PathBuf>::new());
+    }
+
+    #[test]
+    fn load_returns_empty_on_corrupt_json() {
+        let path = unique_temp_file();
+        fs::write(&path, "not valid json").unwrap();
+        assert_eq!(RecentProjects::load(&path), Vec::<PathBuf>::new());
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn push_persists_and_reloads_from_disk() {
+        let path = unique_temp_file();
+        let recent = RecentProjects::new(Some(path.clone()));
+        recent.push(PathBuf::from("/a"));
+        recent.push(PathBuf::from("/b"));
+
+        let reloaded = RecentProjects::new(Some(path.clone()));
+        assert_eq!(
+            reloaded.snapshot(),
+            vec![PathBuf::from("/b"), PathBuf::from("/a")]
+        );
+        let _ = fs::remove_file(&path);
+    }
+}